@@ -1,3 +1,6 @@
+use std::cmp::Ordering;
+use std::ops::{Bound, RangeBounds};
+
 #[derive(Debug, Clone, Eq, PartialEq, PartialOrd, Ord)]
 pub struct BinaryVec<T> {
     vec: Vec<T>,
@@ -135,6 +138,232 @@ impl<T: Ord> BinaryVec<T> {
     pub fn last(&self) -> Option<&T> {
         self.vec.last()
     }
+
+    /// Returns the subslice of elements whose value falls within `range`, computed in O(log n)
+    /// by binary-searching for the lower and upper bounds rather than scanning, taking advantage
+    /// of the vec always being sorted. An empty range (or one with no matching elements) returns
+    /// an empty slice; an unbounded start/end maps to index `0`/`len()`.
+    pub fn range<R: RangeBounds<T>>(&self, range: R) -> &[T] {
+        let (lower, upper) = self.range_bounds(&range);
+        &self.vec[lower..upper]
+    }
+
+    /// Like [`range`](BinaryVec::range), but returns a mutable subslice.
+    ///
+    /// Mutating an element through the returned slice in a way that changes its relative order
+    /// breaks the sorted invariant.
+    pub fn range_mut<R: RangeBounds<T>>(&mut self, range: R) -> &mut [T] {
+        let (lower, upper) = self.range_bounds(&range);
+        &mut self.vec[lower..upper]
+    }
+
+    /// Returns the number of elements whose value falls within `range`, in O(log n), without
+    /// materializing the subslice.
+    pub fn count_in_range<R: RangeBounds<T>>(&self, range: R) -> usize {
+        let (lower, upper) = self.range_bounds(&range);
+        upper - lower
+    }
+
+    /// Computes the `[lower, upper)` index bounds matching `range` via `partition_point`.
+    fn range_bounds<R: RangeBounds<T>>(&self, range: &R) -> (usize, usize) {
+        let lower = match range.start_bound() {
+            Bound::Included(start) => self.vec.partition_point(|x| x < start),
+            Bound::Excluded(start) => self.vec.partition_point(|x| x <= start),
+            Bound::Unbounded => 0,
+        };
+        let upper = match range.end_bound() {
+            Bound::Included(end) => self.vec.partition_point(|x| x <= end),
+            Bound::Excluded(end) => self.vec.partition_point(|x| x < end),
+            Bound::Unbounded => self.vec.len(),
+        };
+
+        (lower, upper.max(lower))
+    }
+
+    /// Returns a new `BinaryVec` containing every element present in `self`, `other`, or both,
+    /// computed with a single O(n+m) linear merge walk over the two already-sorted, deduplicated
+    /// slices rather than concatenating and re-sorting.
+    pub fn union(&self, other: &BinaryVec<T>) -> BinaryVec<T>
+    where
+        T: Clone,
+    {
+        let mut result = Vec::with_capacity(self.vec.len() + other.vec.len());
+        let (mut i, mut j) = (0, 0);
+
+        while i < self.vec.len() && j < other.vec.len() {
+            match self.vec[i].cmp(&other.vec[j]) {
+                Ordering::Less => {
+                    result.push(self.vec[i].clone());
+                    i += 1;
+                }
+                Ordering::Greater => {
+                    result.push(other.vec[j].clone());
+                    j += 1;
+                }
+                Ordering::Equal => {
+                    result.push(self.vec[i].clone());
+                    i += 1;
+                    j += 1;
+                }
+            }
+        }
+
+        result.extend(self.vec[i..].iter().cloned());
+        result.extend(other.vec[j..].iter().cloned());
+
+        BinaryVec { vec: result }
+    }
+
+    /// Returns a new `BinaryVec` containing only the elements present in both `self` and
+    /// `other`, computed with a single O(n+m) linear merge walk; see [`union`](BinaryVec::union).
+    pub fn intersection(&self, other: &BinaryVec<T>) -> BinaryVec<T>
+    where
+        T: Clone,
+    {
+        let mut result = Vec::new();
+        let (mut i, mut j) = (0, 0);
+
+        while i < self.vec.len() && j < other.vec.len() {
+            match self.vec[i].cmp(&other.vec[j]) {
+                Ordering::Less => i += 1,
+                Ordering::Greater => j += 1,
+                Ordering::Equal => {
+                    result.push(self.vec[i].clone());
+                    i += 1;
+                    j += 1;
+                }
+            }
+        }
+
+        BinaryVec { vec: result }
+    }
+
+    /// Returns a new `BinaryVec` containing the elements of `self` that are not in `other`,
+    /// computed with a single O(n+m) linear merge walk; see [`union`](BinaryVec::union).
+    pub fn difference(&self, other: &BinaryVec<T>) -> BinaryVec<T>
+    where
+        T: Clone,
+    {
+        let mut result = Vec::new();
+        let (mut i, mut j) = (0, 0);
+
+        while i < self.vec.len() && j < other.vec.len() {
+            match self.vec[i].cmp(&other.vec[j]) {
+                Ordering::Less => {
+                    result.push(self.vec[i].clone());
+                    i += 1;
+                }
+                Ordering::Greater => j += 1,
+                Ordering::Equal => {
+                    i += 1;
+                    j += 1;
+                }
+            }
+        }
+
+        result.extend(self.vec[i..].iter().cloned());
+
+        BinaryVec { vec: result }
+    }
+
+    /// Returns a new `BinaryVec` containing the elements that are in exactly one of `self` or
+    /// `other`, computed with a single O(n+m) linear merge walk; see [`union`](BinaryVec::union).
+    pub fn symmetric_difference(&self, other: &BinaryVec<T>) -> BinaryVec<T>
+    where
+        T: Clone,
+    {
+        let mut result = Vec::new();
+        let (mut i, mut j) = (0, 0);
+
+        while i < self.vec.len() && j < other.vec.len() {
+            match self.vec[i].cmp(&other.vec[j]) {
+                Ordering::Less => {
+                    result.push(self.vec[i].clone());
+                    i += 1;
+                }
+                Ordering::Greater => {
+                    result.push(other.vec[j].clone());
+                    j += 1;
+                }
+                Ordering::Equal => {
+                    i += 1;
+                    j += 1;
+                }
+            }
+        }
+
+        result.extend(self.vec[i..].iter().cloned());
+        result.extend(other.vec[j..].iter().cloned());
+
+        BinaryVec { vec: result }
+    }
+
+    /// Inserts a value into the `BinaryVec` without deduplicating, keeping the vec sorted and
+    /// preserving insertion order among equal elements (it is placed after any existing elements
+    /// it compares equal to). Opt into this, [`count`](BinaryVec::count), and
+    /// [`remove_all`](BinaryVec::remove_all) to use a `BinaryVec` as a sorted multiset; plain
+    /// [`insert`](BinaryVec::insert) discards duplicates instead.
+    pub fn insert_multi(&mut self, value: T) -> usize {
+        let index = self.vec.partition_point(|x| x <= &value);
+        self.vec.insert(index, value);
+
+        index
+    }
+
+    /// Returns the number of elements equal to `value`, computed in O(log n) as the gap between
+    /// the lower-bound and upper-bound partition points.
+    pub fn count(&self, value: &T) -> usize {
+        let lower = self.vec.partition_point(|x| x < value);
+        let upper = self.vec.partition_point(|x| x <= value);
+
+        upper - lower
+    }
+
+    /// Removes every element equal to `value` in a single drain, returning the removed elements
+    /// in order (or an empty `Vec` if none were found).
+    pub fn remove_all(&mut self, value: &T) -> Vec<T> {
+        let lower = self.vec.partition_point(|x| x < value);
+        let upper = self.vec.partition_point(|x| x <= value);
+
+        self.vec.drain(lower..upper).collect()
+    }
+
+    /// Removes and returns the smallest element, or `None` if the `BinaryVec` is empty.
+    ///
+    /// Because a `BinaryVec` stays sorted, this doubles as a min-priority-queue pop, the
+    /// dominant use case for a `BinaryHeap` (e.g. Dijkstra's shortest-path). Unlike a
+    /// `BinaryHeap` it also exposes [`pop_last`](BinaryVec::pop_last) for O(1) access to the
+    /// maximum at the same time, making `BinaryVec` usable as a min-max priority queue. Note that
+    /// `pop_first` is O(n), since removing the first element shifts the rest down; workloads that
+    /// only ever pop in cost order should store reversed keys so they can pop from the cheap end
+    /// with `pop_last` instead.
+    pub fn pop_first(&mut self) -> Option<T> {
+        if self.vec.is_empty() {
+            None
+        } else {
+            Some(self.vec.remove(0))
+        }
+    }
+
+    /// Removes and returns the largest element, or `None` if the `BinaryVec` is empty.
+    ///
+    /// This is O(1), unlike [`pop_first`](BinaryVec::pop_first), since it removes from the end of
+    /// the backing vec.
+    pub fn pop_last(&mut self) -> Option<T> {
+        self.vec.pop()
+    }
+
+    /// Returns a reference to the smallest element without removing it, or `None` if the
+    /// `BinaryVec` is empty. An alias for [`first`](BinaryVec::first).
+    pub fn peek_first(&self) -> Option<&T> {
+        self.first()
+    }
+
+    /// Returns a reference to the largest element without removing it, or `None` if the
+    /// `BinaryVec` is empty. An alias for [`last`](BinaryVec::last).
+    pub fn peek_last(&self) -> Option<&T> {
+        self.last()
+    }
 }
 
 impl<T: Ord> Default for BinaryVec<T> {
@@ -152,6 +381,234 @@ impl<T> IntoIterator for BinaryVec<T> {
     }
 }
 
+impl<T: Ord> Extend<T> for BinaryVec<T> {
+    /// Extends the `BinaryVec` with the contents of an iterator, keeping the vec sorted.
+    ///
+    /// Rather than inserting one element at a time, this sorts the newly appended elements and
+    /// merges them against the already-sorted prefix in a single linear pass, the way
+    /// [`FromIterator`] builds a `BinaryVec` in O(n log n) instead of the O(n²) cost of repeated
+    /// [`insert`](BinaryVec::insert) calls. Equal elements are deduplicated, with an element
+    /// already present taking precedence over an equal one from `iter`, matching `insert`'s
+    /// "return the existing index on duplicate" semantics.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut tail: Vec<T> = iter.into_iter().collect();
+
+        if tail.is_empty() {
+            return;
+        }
+
+        tail.sort();
+        tail.dedup();
+
+        if self.vec.is_empty() {
+            self.vec = tail;
+            return;
+        }
+
+        let mut merged = Vec::with_capacity(self.vec.len() + tail.len());
+        {
+            let mut prefix = self.vec.drain(..).peekable();
+            let mut tail = tail.into_iter().peekable();
+
+            loop {
+                match (prefix.peek(), tail.peek()) {
+                    (Some(p), Some(t)) => match p.cmp(t) {
+                        Ordering::Less => merged.push(prefix.next().unwrap()),
+                        Ordering::Greater => merged.push(tail.next().unwrap()),
+                        Ordering::Equal => {
+                            merged.push(prefix.next().unwrap());
+                            tail.next();
+                        }
+                    },
+                    (Some(_), None) => merged.push(prefix.next().unwrap()),
+                    (None, Some(_)) => merged.push(tail.next().unwrap()),
+                    (None, None) => break,
+                }
+            }
+        }
+
+        self.vec = merged;
+    }
+}
+
+impl<T: Ord> FromIterator<T> for BinaryVec<T> {
+    /// Builds a `BinaryVec` from an iterator in O(n log n) by collecting, sorting once, and
+    /// deduplicating, rather than paying the O(n²) cost of inserting each item one at a time.
+    /// This mirrors the in-place heap-construction note in the standard `BinaryHeap` docs, where
+    /// converting a vector is O(n) rather than n individual pushes.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut vec: Vec<T> = iter.into_iter().collect();
+        vec.sort();
+        vec.dedup();
+
+        BinaryVec { vec }
+    }
+}
+
+impl<T: Ord> From<Vec<T>> for BinaryVec<T> {
+    /// Builds a `BinaryVec` from a `Vec<T>` in O(n log n); see [`FromIterator`].
+    fn from(vec: Vec<T>) -> Self {
+        vec.into_iter().collect()
+    }
+}
+
+/// A `BinaryVec` variant that orders its elements with a user-supplied comparator instead of
+/// `T`'s `Ord` implementation, the way a `BinaryHeap` is turned into a min-heap by flipping
+/// `Ord` with `Reverse`. This makes it possible to keep a vec sorted by a derived key, or in
+/// reverse, without requiring `T: Ord` at all.
+///
+/// The comparator is threaded through every lookup (`insert`, `get_index`, `contains`,
+/// `remove_item`), so it must stay consistent for the lifetime of the `BinaryVecBy`: mutating an
+/// element through [`iter_mut`](BinaryVecBy::iter_mut) or
+/// [`as_mut_slice`](BinaryVecBy::as_mut_slice) in a way that changes how it compares against the
+/// others breaks the sorted invariant.
+pub struct BinaryVecBy<T, F> {
+    vec: Vec<T>,
+    compare: F,
+}
+
+impl<T, F> BinaryVecBy<T, F>
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    /// Creates a new empty `BinaryVecBy` ordered by `compare`.
+    pub fn new_by(compare: F) -> Self {
+        BinaryVecBy {
+            vec: Vec::new(),
+            compare,
+        }
+    }
+
+    /// Returns the vector internally used by `BinaryVecBy`.
+    pub fn into_vec(self) -> Vec<T> {
+        self.vec
+    }
+
+    /// Returns an iterator over references to the items in the `BinaryVecBy`.
+    pub fn iter(&self) -> std::slice::Iter<T> {
+        self.vec.iter()
+    }
+
+    /// Returns an iterator over mutable references to the items in the `BinaryVecBy`.
+    ///
+    /// Changing an element's key through this iterator breaks the sorted invariant; see the
+    /// type-level docs.
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<T> {
+        self.vec.iter_mut()
+    }
+
+    /// Inserts a value into the `BinaryVecBy`, maintaining the order defined by `compare`, and
+    /// returns the index where the value was inserted.
+    pub fn insert(&mut self, value: T) -> usize {
+        match self
+            .vec
+            .binary_search_by(|probe| (self.compare)(probe, &value))
+        {
+            Ok(index) => index,
+            Err(index) => {
+                self.vec.insert(index, value);
+
+                index
+            }
+        }
+    }
+
+    /// Returns the item at the specified index, or `None` if the index is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.vec.get(index)
+    }
+
+    /// Returns the index of the specified value, or `None` if the value is not found.
+    pub fn get_index(&self, value: &T) -> Option<usize> {
+        self.vec
+            .binary_search_by(|probe| (self.compare)(probe, value))
+            .ok()
+    }
+
+    /// Removes the item at the specified index, returning it if it exists, or `None` if the index
+    /// is out of bounds.
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if index < self.vec.len() {
+            Some(self.vec.remove(index))
+        } else {
+            None
+        }
+    }
+
+    /// Removes the specified item from the `BinaryVecBy`, returning it if it exists, or `None` if
+    /// the item is not found.
+    pub fn remove_item(&mut self, value: &T) -> Option<T> {
+        match self
+            .vec
+            .binary_search_by(|probe| (self.compare)(probe, value))
+        {
+            Ok(index) => Some(self.vec.remove(index)),
+            Err(_) => None,
+        }
+    }
+
+    /// Checks if the `BinaryVecBy` contains the specified value.
+    pub fn contains(&self, value: &T) -> bool {
+        self.vec
+            .binary_search_by(|probe| (self.compare)(probe, value))
+            .is_ok()
+    }
+
+    /// Returns the number of elements in the `BinaryVecBy`.
+    pub fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    /// Checks if the `BinaryVecBy` is empty.
+    pub fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+
+    /// Clears the `BinaryVecBy`, removing all elements.
+    pub fn clear(&mut self) {
+        self.vec.clear();
+    }
+
+    /// Returns a reference to the underlying vector.
+    pub fn as_slice(&self) -> &[T] {
+        &self.vec
+    }
+
+    /// Returns a mutable reference to the underlying vector.
+    ///
+    /// Changing an element's key through this slice breaks the sorted invariant; see the
+    /// type-level docs.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.vec
+    }
+
+    /// Returns the first element of the `BinaryVecBy`, or `None` if it is empty.
+    pub fn first(&self) -> Option<&T> {
+        self.vec.first()
+    }
+
+    /// Returns the last element of the `BinaryVecBy`, or `None` if it is empty.
+    pub fn last(&self) -> Option<&T> {
+        self.vec.last()
+    }
+}
+
+impl<T> BinaryVecBy<T, Box<dyn Fn(&T, &T) -> Ordering>> {
+    /// Creates a new empty `BinaryVecBy` ordered by the key that `key_fn` extracts from each
+    /// element, e.g. `BinaryVecBy::new_by_key(|t: &Task| t.priority)` for a derived or reversed
+    /// order that a plain `BinaryVec<T>` can't express.
+    pub fn new_by_key<K, KF>(key_fn: KF) -> Self
+    where
+        K: Ord,
+        KF: Fn(&T) -> K + 'static,
+    {
+        BinaryVecBy {
+            vec: Vec::new(),
+            compare: Box::new(move |a, b| key_fn(a).cmp(&key_fn(b))),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -205,4 +662,102 @@ mod tests {
         assert_eq!(binary_vec.get_index(&7), Some(2));
         assert_eq!(binary_vec.get_index(&10), None); // Not found
     }
+
+    #[test]
+    fn test_from_iter_sorts_and_dedups() {
+        let binary_vec: BinaryVec<i32> = [5, 3, 7, 3, 1].into_iter().collect();
+        assert_eq!(binary_vec.as_slice(), &[1, 3, 5, 7]);
+    }
+
+    #[test]
+    fn test_from_vec() {
+        let binary_vec = BinaryVec::from(vec![5, 3, 7]);
+        assert_eq!(binary_vec.as_slice(), &[3, 5, 7]);
+    }
+
+    #[test]
+    fn test_extend_merges_sorted_tail_into_prefix() {
+        let mut binary_vec: BinaryVec<i32> = [1, 3, 5].into_iter().collect();
+        binary_vec.extend([4, 3, 0, 6]);
+
+        assert_eq!(binary_vec.as_slice(), &[0, 1, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_range_inclusive_and_exclusive_bounds() {
+        let binary_vec: BinaryVec<i32> = [1, 3, 5, 7, 9].into_iter().collect();
+
+        assert_eq!(binary_vec.range(3..=7), &[3, 5, 7]);
+        assert_eq!(binary_vec.range(3..7), &[3, 5]);
+        assert_eq!(binary_vec.range(..5), &[1, 3]);
+        assert_eq!(binary_vec.range(5..), &[5, 7, 9]);
+        assert_eq!(binary_vec.range(4..4), &[] as &[i32]);
+        assert_eq!(binary_vec.count_in_range(3..=7), 3);
+    }
+
+    #[test]
+    fn test_set_algebra() {
+        let a: BinaryVec<i32> = [1, 2, 3, 4].into_iter().collect();
+        let b: BinaryVec<i32> = [3, 4, 5, 6].into_iter().collect();
+
+        assert_eq!(a.union(&b).as_slice(), &[1, 2, 3, 4, 5, 6]);
+        assert_eq!(a.intersection(&b).as_slice(), &[3, 4]);
+        assert_eq!(a.difference(&b).as_slice(), &[1, 2]);
+        assert_eq!(a.symmetric_difference(&b).as_slice(), &[1, 2, 5, 6]);
+    }
+
+    #[test]
+    fn test_multiset_insert_count_remove_all() {
+        let mut binary_vec = BinaryVec::new();
+        binary_vec.insert_multi(3);
+        binary_vec.insert_multi(1);
+        binary_vec.insert_multi(3);
+        binary_vec.insert_multi(2);
+        binary_vec.insert_multi(3);
+
+        assert_eq!(binary_vec.as_slice(), &[1, 2, 3, 3, 3]);
+        assert_eq!(binary_vec.count(&3), 3);
+        assert_eq!(binary_vec.count(&9), 0);
+
+        assert_eq!(binary_vec.remove_all(&3), vec![3, 3, 3]);
+        assert_eq!(binary_vec.as_slice(), &[1, 2]);
+    }
+
+    #[test]
+    fn test_pop_and_peek_min_max() {
+        let mut binary_vec: BinaryVec<i32> = [5, 1, 3].into_iter().collect();
+
+        assert_eq!(binary_vec.peek_first(), Some(&1));
+        assert_eq!(binary_vec.peek_last(), Some(&5));
+        assert_eq!(binary_vec.pop_first(), Some(1));
+        assert_eq!(binary_vec.pop_last(), Some(5));
+        assert_eq!(binary_vec.as_slice(), &[3]);
+        assert_eq!(binary_vec.pop_first(), Some(3));
+        assert_eq!(binary_vec.pop_last(), None);
+    }
+
+    #[test]
+    fn test_binary_vec_by_reverse_order() {
+        let mut by = BinaryVecBy::new_by(|a: &i32, b: &i32| b.cmp(a));
+        by.insert(5);
+        by.insert(3);
+        by.insert(7);
+
+        assert_eq!(by.as_slice(), &[7, 5, 3]);
+        assert_eq!(by.get_index(&5), Some(1));
+        assert!(by.contains(&7));
+        assert_eq!(by.remove_item(&5), Some(5));
+        assert_eq!(by.as_slice(), &[7, 3]);
+    }
+
+    #[test]
+    fn test_binary_vec_by_key() {
+        let mut by = BinaryVecBy::new_by_key(|pair: &(i32, &str)| pair.0);
+        by.insert((2, "b"));
+        by.insert((1, "a"));
+        by.insert((3, "c"));
+
+        assert_eq!(by.as_slice(), &[(1, "a"), (2, "b"), (3, "c")]);
+        assert_eq!(by.get_index(&(2, "b")), Some(1));
+    }
 }